@@ -1,13 +1,77 @@
 //! This lib wraps svn command line tool on your system
 #![warn(missing_docs)]
 #![warn(unsafe_code)]
-use std::process::Command;
+use std::io::{BufRead, Read, Write};
+use std::process::{Command, Stdio};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum SvnError {
     #[error("Failed to run svn command: {0}")]
     CommandFailed(String),
+    #[error("Failed to parse svn --xml output: {0}")]
+    XmlParse(#[from] quick_xml::de::DeError),
+    #[error("Failed to parse svn timestamp {0:?}: {1}")]
+    DateParse(String, chrono::ParseError),
+    #[error("svn command failed because a required lock is missing or held by someone else: {0}")]
+    LockRequired(String),
+    #[error("file content is not valid UTF-8 text: {0}")]
+    Malformed(std::str::Utf8Error),
+    #[error("file has mixed line endings and `repair` was not set")]
+    MixedEol,
+}
+
+/// Wraps a failed `svn` invocation's stderr as [`SvnError::LockRequired`] when
+/// it looks lock-related, or [`SvnError::CommandFailed`] otherwise.
+///
+/// The check is narrower than a bare `contains("lock")`: svn also uses that
+/// word for an unrelated working-copy administrative lock (e.g. "...locked,
+/// run 'svn cleanup'"), which is a different problem and must not be
+/// misreported as [`SvnError::LockRequired`].
+fn classify_failure(stderr: String) -> SvnError {
+    let lowered = stderr.to_lowercase();
+
+    if lowered.contains("run 'svn cleanup'") {
+        SvnError::CommandFailed(stderr)
+    } else if lowered.contains("lock") {
+        SvnError::LockRequired(stderr)
+    } else {
+        SvnError::CommandFailed(stderr)
+    }
+}
+
+/// A revision specifier accepted by `-r` on most `svn` subcommands.
+pub enum Revision {
+    /// The latest revision in the repository.
+    Head,
+    /// The revision the working copy is currently based on.
+    Base,
+    /// An explicit revision number.
+    Number(u32),
+    /// The youngest revision as of the given point in time.
+    Date(DateTime<Utc>),
+}
+
+impl Revision {
+    /// Renders this revision the way `svn -r` expects on the command line.
+    fn as_arg(&self) -> String {
+        match self {
+            Revision::Head => "HEAD".to_owned(),
+            Revision::Base => "BASE".to_owned(),
+            Revision::Number(rev) => rev.to_string(),
+            Revision::Date(date) => format!("{{{}}}", date.to_rfc3339()),
+        }
+    }
+}
+
+/// Parses an SVN ISO-8601 timestamp (`YYYY-MM-DDTHH:MM:SS.ffffffZ`) as emitted
+/// by `svn_time_to_cstring`, which is always UTC.
+fn parse_svn_date(raw: &str) -> Result<DateTime<Utc>, SvnError> {
+    DateTime::parse_from_rfc3339(raw)
+        .map(|date| date.with_timezone(&Utc))
+        .map_err(|e| SvnError::DateParse(raw.to_owned(), e))
 }
 
 /// Returns the version of svn command line tool
@@ -20,25 +84,194 @@ pub fn version() -> String {
     String::from_utf8_lossy(&output.stdout).into_owned()
 }
 
+/// How deeply an operation should recurse into a working copy, mirroring
+/// svn's `--depth` flag.
+pub enum Depth {
+    /// Just the target itself, no children.
+    Empty,
+    /// The target and any immediate file children, but no subdirectories.
+    Files,
+    /// The target and its immediate children, without recursing into them.
+    Immediates,
+    /// The target and everything beneath it.
+    Infinity,
+}
+
+impl Depth {
+    fn as_arg(&self) -> &'static str {
+        match self {
+            Depth::Empty => "empty",
+            Depth::Files => "files",
+            Depth::Immediates => "immediates",
+            Depth::Infinity => "infinity",
+        }
+    }
+}
+
+/// Builds an [`SvnWrapper`] carrying global options applied to every command
+/// it runs.
+pub struct SvnWrapperBuilder {
+    svn_bin: String,
+    username: Option<String>,
+    password: Option<String>,
+    non_interactive: bool,
+    trust_server_cert_failures: Option<String>,
+    config_dir: Option<String>,
+}
+
+impl SvnWrapperBuilder {
+    /// Sets the `svn` binary to invoke. Defaults to `svn` on `PATH`.
+    pub fn svn_bin(mut self, path: &str) -> Self {
+        self.svn_bin = path.to_owned();
+        self
+    }
+
+    /// Sets `--username` for every command.
+    pub fn username(mut self, username: &str) -> Self {
+        self.username = Some(username.to_owned());
+        self
+    }
+
+    /// Sets `--password` for every command.
+    pub fn password(mut self, password: &str) -> Self {
+        self.password = Some(password.to_owned());
+        self
+    }
+
+    /// Passes `--non-interactive`, so svn never prompts for credentials or
+    /// confirmation. Required to drive this crate safely in CI.
+    pub fn non_interactive(mut self, non_interactive: bool) -> Self {
+        self.non_interactive = non_interactive;
+        self
+    }
+
+    /// Sets `--trust-server-cert-failures` to the given comma-separated
+    /// failure list (e.g. `"unknown-ca,cn-mismatch"`).
+    pub fn trust_server_cert_failures(mut self, failures: &str) -> Self {
+        self.trust_server_cert_failures = Some(failures.to_owned());
+        self
+    }
+
+    /// Sets `--config-dir` for every command.
+    pub fn config_dir(mut self, path: &str) -> Self {
+        self.config_dir = Some(path.to_owned());
+        self
+    }
+
+    /// Finishes building the wrapper.
+    pub fn build(self) -> SvnWrapper {
+        SvnWrapper { options: self }
+    }
+}
+
+impl Default for SvnWrapperBuilder {
+    fn default() -> Self {
+        Self {
+            svn_bin: "svn".to_owned(),
+            username: None,
+            password: None,
+            non_interactive: false,
+            trust_server_cert_failures: None,
+            config_dir: None,
+        }
+    }
+}
+
 /// This struct wraps svn command line tool
-pub struct SvnWrapper {}
+pub struct SvnWrapper {
+    options: SvnWrapperBuilder,
+}
 
 impl SvnWrapper {
     pub fn new() -> Self {
-        Self {}
+        SvnWrapperBuilder::default().build()
+    }
+
+    /// Starts a builder for configuring global options (auth, non-interactive
+    /// mode, a non-default `svn` binary, ...) before any command runs.
+    pub fn builder() -> SvnWrapperBuilder {
+        SvnWrapperBuilder::default()
+    }
+
+    /// Applies this wrapper's global options (auth, non-interactive mode,
+    /// cert trust, config dir) to `command`. Shared by [`SvnWrapper::command`]
+    /// and [`SvnWrapper::svnmucc_command`].
+    ///
+    /// The password, if set, is never passed as an argument (visible to other
+    /// local users via `/proc/<pid>/cmdline`); instead `--password-from-stdin`
+    /// is set here and [`SvnWrapper::run`] writes the password to the child's
+    /// stdin.
+    fn apply_global_options(&self, command: &mut Command) {
+        if let Some(username) = &self.options.username {
+            command.arg("--username").arg(username);
+        }
+
+        if self.options.password.is_some() {
+            command.arg("--password-from-stdin");
+        }
+
+        if self.options.non_interactive {
+            command.arg("--non-interactive");
+        }
+
+        if let Some(failures) = &self.options.trust_server_cert_failures {
+            command.arg("--trust-server-cert-failures").arg(failures);
+        }
+
+        if let Some(config_dir) = &self.options.config_dir {
+            command.arg("--config-dir").arg(config_dir);
+        }
+    }
+
+    /// Builds a `Command` for `subcommand` with this wrapper's global options
+    /// already applied.
+    fn command(&self, subcommand: &str) -> Command {
+        let mut command = Command::new(&self.options.svn_bin);
+        command.arg(subcommand);
+        self.apply_global_options(&mut command);
+        command
     }
 
-    pub fn commit(&self, path: &str) -> Result<(), SvnError> {
-        let output = Command::new("svn")
-            .arg("commit")
-            .arg("-m")
-            .arg("\"Committed changes\"")
-            .arg(path)
-            .output()
+    /// Builds a `Command` for `svnmucc` with this wrapper's global options
+    /// already applied, for use by [`SvnMucc::commit`].
+    fn svnmucc_command(&self) -> Command {
+        let mut command = Command::new("svnmucc");
+        self.apply_global_options(&mut command);
+        command
+    }
+
+    /// Runs `command` to completion, piping the configured password to its
+    /// stdin first if one is set. Used by every non-streaming method instead
+    /// of calling `.output()` directly.
+    fn run(&self, mut command: Command) -> Result<std::process::Output, SvnError> {
+        if self.options.password.is_some() {
+            command.stdin(Stdio::piped());
+        }
+
+        command.stdout(Stdio::piped()).stderr(Stdio::piped());
+
+        let mut child = command
+            .spawn()
             .map_err(|e| SvnError::CommandFailed(e.to_string()))?;
 
+        if let Some(password) = &self.options.password {
+            let mut stdin = child.stdin.take().expect("stdin was piped");
+            writeln!(stdin, "{password}").map_err(|e| SvnError::CommandFailed(e.to_string()))?;
+            drop(stdin);
+        }
+
+        child
+            .wait_with_output()
+            .map_err(|e| SvnError::CommandFailed(e.to_string()))
+    }
+
+    pub fn commit(&self, path: &str, message: &str) -> Result<(), SvnError> {
+        let mut command = self.command("commit");
+        command.arg("-m").arg(message).arg(path);
+        let output = self.run(command)?;
+
         if !output.status.success() {
-            return Err(SvnError::CommandFailed(
+            return Err(classify_failure(
                 String::from_utf8_lossy(&output.stderr).to_string(),
             ));
         }
@@ -46,16 +279,14 @@ impl SvnWrapper {
         Ok(())
     }
 
-    pub fn checkout(&self, url: &str, path: &str) -> Result<(), SvnError> {
-        let output = Command::new("svn")
-            .arg("checkout")
-            .arg(url)
-            .arg(path)
-            .output()
-            .map_err(|e| SvnError::CommandFailed(e.to_string()))?;
+    /// Schedules `path` for addition at the next commit.
+    pub fn add(&self, path: &str, depth: Depth) -> Result<(), SvnError> {
+        let mut command = self.command("add");
+        command.arg("--depth").arg(depth.as_arg()).arg(path);
+        let output = self.run(command)?;
 
         if !output.status.success() {
-            return Err(SvnError::CommandFailed(
+            return Err(classify_failure(
                 String::from_utf8_lossy(&output.stderr).to_string(),
             ));
         }
@@ -63,15 +294,21 @@ impl SvnWrapper {
         Ok(())
     }
 
-    pub fn update(&self, path: &str) -> Result<(), SvnError> {
-        let output = Command::new("svn")
-            .arg("update")
-            .arg(path)
-            .output()
-            .map_err(|e| SvnError::CommandFailed(e.to_string()))?;
+    /// Locks `path`, optionally stealing any lock already held by someone
+    /// else when `steal` is `true`.
+    pub fn lock(&self, path: &str, comment: &str, steal: bool) -> Result<(), SvnError> {
+        let mut command = self.command("lock");
+        command.arg("-m").arg(comment);
+
+        if steal {
+            command.arg("--force");
+        }
+
+        command.arg(path);
+        let output = self.run(command)?;
 
         if !output.status.success() {
-            return Err(SvnError::CommandFailed(
+            return Err(classify_failure(
                 String::from_utf8_lossy(&output.stderr).to_string(),
             ));
         }
@@ -79,21 +316,435 @@ impl SvnWrapper {
         Ok(())
     }
 
-    pub fn log(&self, path: &str) -> Result<String, SvnError> {
-        let output = Command::new("svn")
-            .arg("log")
-            .arg(path)
-            .output()
-            .map_err(|e| SvnError::CommandFailed(e.to_string()))?;
+    /// Unlocks `path`. Set `break_lock` to release a lock owned by someone
+    /// else rather than the current user.
+    pub fn unlock(&self, path: &str, break_lock: bool) -> Result<(), SvnError> {
+        let mut command = self.command("unlock");
+
+        if break_lock {
+            command.arg("--force");
+        }
+
+        command.arg(path);
+        let output = self.run(command)?;
 
         if !output.status.success() {
-            return Err(SvnError::CommandFailed(
+            return Err(classify_failure(
+                String::from_utf8_lossy(&output.stderr).to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub fn checkout(&self, url: &str, path: &str, depth: Depth) -> Result<(), SvnError> {
+        let mut command = self.command("checkout");
+        command.arg("--depth").arg(depth.as_arg()).arg(url).arg(path);
+        let output = self.run(command)?;
+
+        if !output.status.success() {
+            return Err(classify_failure(
+                String::from_utf8_lossy(&output.stderr).to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Like [`SvnWrapper::checkout`], but streams `svn`'s per-item
+    /// notifications to `on_notify` as they arrive instead of buffering all
+    /// output until completion. Returns the revision checked out to.
+    pub fn checkout_streaming(
+        &self,
+        url: &str,
+        path: &str,
+        depth: Depth,
+        on_notify: impl FnMut(Notification),
+    ) -> Result<u32, SvnError> {
+        let mut command = self.command("checkout");
+        command.arg("--depth").arg(depth.as_arg()).arg(url).arg(path);
+
+        run_streaming(
+            command,
+            self.options.password.as_deref(),
+            on_notify,
+            "Checked out revision ",
+        )
+    }
+
+    pub fn update(
+        &self,
+        path: &str,
+        revision: Option<Revision>,
+        depth: Depth,
+    ) -> Result<(), SvnError> {
+        let mut command = self.command("update");
+        command.arg("--depth").arg(depth.as_arg());
+
+        if let Some(revision) = revision {
+            command.arg("-r").arg(revision.as_arg());
+        }
+
+        command.arg(path);
+        let output = self.run(command)?;
+
+        if !output.status.success() {
+            return Err(classify_failure(
+                String::from_utf8_lossy(&output.stderr).to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Like [`SvnWrapper::update`], but streams `svn`'s per-item
+    /// notifications to `on_notify` as they arrive instead of buffering all
+    /// output until completion. Returns the revision updated to.
+    pub fn update_streaming(
+        &self,
+        path: &str,
+        revision: Option<Revision>,
+        depth: Depth,
+        on_notify: impl FnMut(Notification),
+    ) -> Result<u32, SvnError> {
+        let mut command = self.command("update");
+        command.arg("--depth").arg(depth.as_arg());
+
+        if let Some(revision) = revision {
+            command.arg("-r").arg(revision.as_arg());
+        }
+
+        command.arg(path);
+
+        run_streaming(
+            command,
+            self.options.password.as_deref(),
+            on_notify,
+            "Updated to revision ",
+        )
+    }
+
+    pub fn log(&self, path: &str, revision: Option<Revision>) -> Result<String, SvnError> {
+        let mut command = self.command("log");
+
+        if let Some(revision) = revision {
+            command.arg("-r").arg(revision.as_arg());
+        }
+
+        command.arg(path);
+        let output = self.run(command)?;
+
+        if !output.status.success() {
+            return Err(classify_failure(
                 String::from_utf8_lossy(&output.stderr).to_string(),
             ));
         }
 
         Ok(String::from_utf8_lossy(&output.stdout).to_string())
     }
+
+    /// Runs `svn log` and discards the payload, returning the number of
+    /// log entries, bytes received, and elapsed time.
+    pub fn bench_log(&self, path: &str) -> Result<BenchStats, SvnError> {
+        let start = std::time::Instant::now();
+        let mut command = self.command("log");
+        command.arg(path);
+        let output = self.run(command)?;
+
+        if !output.status.success() {
+            return Err(classify_failure(
+                String::from_utf8_lossy(&output.stderr).to_string(),
+            ));
+        }
+
+        let items = String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter(|line| {
+                line.starts_with('r') && line[1..].starts_with(|c: char| c.is_ascii_digit())
+            })
+            .count();
+
+        Ok(BenchStats {
+            items,
+            bytes: output.stdout.len() as u64,
+            duration: start.elapsed(),
+        })
+    }
+
+    /// Runs `svn list` and discards the payload, returning the number of
+    /// entries listed, bytes received, and elapsed time.
+    pub fn bench_list(&self, path: &str) -> Result<BenchStats, SvnError> {
+        let start = std::time::Instant::now();
+        let mut command = self.command("list");
+        command.arg(path);
+        let output = self.run(command)?;
+
+        if !output.status.success() {
+            return Err(classify_failure(
+                String::from_utf8_lossy(&output.stderr).to_string(),
+            ));
+        }
+
+        let items = String::from_utf8_lossy(&output.stdout).lines().count();
+
+        Ok(BenchStats {
+            items,
+            bytes: output.stdout.len() as u64,
+            duration: start.elapsed(),
+        })
+    }
+
+    /// Benchmarks exporting a tree by listing it recursively, then fetching
+    /// every file's content in a single `svn cat` invocation (which accepts
+    /// multiple targets and streams their content to stdout), never writing
+    /// anything to disk. Returns the number of files and bytes received and
+    /// the elapsed time.
+    ///
+    /// A single `cat` call is used rather than one per file so the result
+    /// reflects server/network throughput rather than being dominated by
+    /// per-file subprocess spawn overhead.
+    pub fn bench_export(&self, url: &str) -> Result<BenchStats, SvnError> {
+        let start = std::time::Instant::now();
+        let mut command = self.command("list");
+        command.arg("--recursive").arg("--xml").arg(url);
+        let output = self.run(command)?;
+
+        if !output.status.success() {
+            return Err(classify_failure(
+                String::from_utf8_lossy(&output.stderr).to_string(),
+            ));
+        }
+
+        let xml = String::from_utf8_lossy(&output.stdout).to_string();
+        let listing: XmlLists = quick_xml::de::from_str(&xml)?;
+
+        let base = url.trim_end_matches('/');
+        let file_urls: Vec<String> = listing
+            .lists
+            .into_iter()
+            .flat_map(|list| list.entries)
+            .filter(|entry| entry.kind == "file")
+            .map(|entry| format!("{base}/{}", entry.name))
+            .collect();
+
+        if file_urls.is_empty() {
+            return Ok(BenchStats {
+                items: 0,
+                bytes: 0,
+                duration: start.elapsed(),
+            });
+        }
+
+        let mut cat_command = self.command("cat");
+        cat_command.args(&file_urls);
+        let output = self.run(cat_command)?;
+
+        if !output.status.success() {
+            return Err(classify_failure(
+                String::from_utf8_lossy(&output.stderr).to_string(),
+            ));
+        }
+
+        Ok(BenchStats {
+            items: file_urls.len(),
+            bytes: output.stdout.len() as u64,
+            duration: start.elapsed(),
+        })
+    }
+}
+
+/// Top-level `<lists>` element of `svn list --xml`.
+#[derive(Deserialize)]
+struct XmlLists {
+    #[serde(rename = "list", default)]
+    lists: Vec<XmlList>,
+}
+
+/// One `<list>` element, holding every entry under the listed path.
+#[derive(Deserialize)]
+struct XmlList {
+    #[serde(rename = "entry", default)]
+    entries: Vec<XmlListEntry>,
+}
+
+/// One `<entry>` of `svn list --xml`.
+#[derive(Deserialize)]
+struct XmlListEntry {
+    #[serde(rename = "@kind")]
+    kind: String,
+    name: String,
+}
+
+/// The kind of per-item event reported while an `svn` operation is running.
+pub enum NotificationAction {
+    /// `A path` — the item was added.
+    Added,
+    /// `U path` — the item was updated.
+    Updated,
+    /// `D path` — the item was deleted.
+    Deleted,
+    /// `C path` — the item is in conflict.
+    Conflicted,
+    /// `G path` — the item was merged.
+    Merged,
+}
+
+/// A single notification line emitted by a streaming checkout/update, e.g.
+/// `U src/main.rs`.
+pub struct Notification {
+    pub action: NotificationAction,
+    pub path: String,
+}
+
+fn parse_notification(line: &str) -> Option<Notification> {
+    let code = line.chars().next()?;
+
+    if !matches!(line.as_bytes().get(1), Some(b' ')) {
+        return None;
+    }
+
+    let action = match code {
+        'A' => NotificationAction::Added,
+        'U' => NotificationAction::Updated,
+        'D' => NotificationAction::Deleted,
+        'C' => NotificationAction::Conflicted,
+        'G' => NotificationAction::Merged,
+        _ => return None,
+    };
+
+    let path = line[1..].trim_start();
+
+    if path.is_empty() {
+        return None;
+    }
+
+    Some(Notification {
+        action,
+        path: path.to_owned(),
+    })
+}
+
+/// Svn's final summary line is either `"<prefix>N."` (a real checkout/update)
+/// or `"At revision N."` (a no-op update that left the working copy as-is).
+fn parse_final_revision(line: &str, prefix: &str) -> Option<u32> {
+    let rest = line
+        .strip_prefix(prefix)
+        .or_else(|| line.strip_prefix("At revision "))?;
+    let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+
+    digits.parse().ok()
+}
+
+/// Spawns `command`, feeding each stdout line to `parse_notification` and
+/// forwarding any match to `on_notify`, and returns the final revision number
+/// reported on the `final_prefix` (or `"At revision "`) summary line.
+///
+/// stdout and stderr are drained concurrently: reading stdout line-by-line
+/// while leaving stderr unread would deadlock once svn fills the stderr
+/// pipe buffer (e.g. on a large checkout/update that also emits warnings).
+fn run_streaming(
+    mut command: Command,
+    password: Option<&str>,
+    mut on_notify: impl FnMut(Notification),
+    final_prefix: &str,
+) -> Result<u32, SvnError> {
+    command.stdout(Stdio::piped()).stderr(Stdio::piped());
+
+    if password.is_some() {
+        command.stdin(Stdio::piped());
+    }
+
+    let mut child = command
+        .spawn()
+        .map_err(|e| SvnError::CommandFailed(e.to_string()))?;
+
+    if let Some(password) = password {
+        let mut stdin = child.stdin.take().expect("stdin was piped");
+        writeln!(stdin, "{password}").map_err(|e| SvnError::CommandFailed(e.to_string()))?;
+        drop(stdin);
+    }
+
+    let stdout = child.stdout.take().expect("stdout was piped");
+    let mut stderr = child.stderr.take().expect("stderr was piped");
+
+    let stderr_reader = std::thread::spawn(move || {
+        let mut buf = Vec::new();
+        let _ = stderr.read_to_end(&mut buf);
+        buf
+    });
+
+    let mut revision = None;
+
+    for line in std::io::BufReader::new(stdout).lines() {
+        let line = line.map_err(|e| SvnError::CommandFailed(e.to_string()))?;
+
+        if let Some(notification) = parse_notification(&line) {
+            on_notify(notification);
+        } else if let Some(rev) = parse_final_revision(&line, final_prefix) {
+            revision = Some(rev);
+        }
+    }
+
+    let stderr_bytes = stderr_reader.join().unwrap_or_default();
+    let status = child
+        .wait()
+        .map_err(|e| SvnError::CommandFailed(e.to_string()))?;
+
+    if !status.success() {
+        return Err(classify_failure(
+            String::from_utf8_lossy(&stderr_bytes).to_string(),
+        ));
+    }
+
+    revision.ok_or_else(|| SvnError::CommandFailed("svn did not report a final revision".to_owned()))
+}
+
+/// Counters and timing returned by `SvnWrapper`'s `bench_*` methods.
+pub struct BenchStats {
+    /// Number of revisions/paths traversed.
+    pub items: usize,
+    /// Bytes received from the server.
+    pub bytes: u64,
+    /// Wall-clock time the operation took.
+    pub duration: std::time::Duration,
+}
+
+/// One `<entry>` of `svn info --xml`, as deserialized by `quick-xml`.
+#[derive(Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct XmlInfoEntry {
+    url: String,
+    repository: XmlRepository,
+    commit: XmlCommit,
+    lock: Option<XmlLock>,
+}
+
+#[derive(Deserialize)]
+struct XmlLock {
+    token: String,
+    owner: String,
+    comment: Option<String>,
+    created: String,
+    expires: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct XmlRepository {
+    root: String,
+}
+
+#[derive(Deserialize)]
+struct XmlCommit {
+    #[serde(rename = "@revision")]
+    revision: u32,
+    author: String,
+    date: String,
+}
+
+#[derive(Deserialize)]
+struct XmlInfo {
+    #[serde(rename = "entry", default)]
+    entries: Vec<XmlInfoEntry>,
 }
 
 pub struct SvnInfo {
@@ -101,90 +752,675 @@ pub struct SvnInfo {
     pub repository_root: String,
     pub last_changed_author: String,
     pub last_changed_rev: u32,
-    pub last_changed_date: String,
+    pub last_changed_date: DateTime<Utc>,
+    /// The raw, unparsed `<commit><date>` string as emitted by `svn info --xml`.
+    pub last_changed_date_raw: String,
+    /// The lock held on this path, if any.
+    pub lock: Option<SvnLock>,
+}
+
+impl TryFrom<XmlInfoEntry> for SvnInfo {
+    type Error = SvnError;
+
+    fn try_from(entry: XmlInfoEntry) -> Result<Self, Self::Error> {
+        Ok(SvnInfo {
+            url: entry.url,
+            repository_root: entry.repository.root,
+            last_changed_author: entry.commit.author,
+            last_changed_rev: entry.commit.revision,
+            last_changed_date: parse_svn_date(&entry.commit.date)?,
+            last_changed_date_raw: entry.commit.date,
+            lock: entry.lock.map(SvnLock::try_from).transpose()?,
+        })
+    }
+}
+
+/// A lock held on a path, as reported by `svn info --xml`'s `<lock>` element.
+pub struct SvnLock {
+    /// The opaque lock token. Required to commit or unlock; breaking the lock
+    /// with [`SvnWrapper::unlock`]'s `break_lock` does not need it.
+    pub token: String,
+    pub owner: String,
+    pub comment: Option<String>,
+    pub created: DateTime<Utc>,
+    pub expires: Option<DateTime<Utc>>,
+}
+
+impl TryFrom<XmlLock> for SvnLock {
+    type Error = SvnError;
+
+    fn try_from(lock: XmlLock) -> Result<Self, Self::Error> {
+        Ok(SvnLock {
+            token: lock.token,
+            owner: lock.owner,
+            comment: lock.comment,
+            created: parse_svn_date(&lock.created)?,
+            expires: lock.expires.map(|e| parse_svn_date(&e)).transpose()?,
+        })
+    }
 }
 
 impl SvnInfo {
-    pub fn new(path: &str) -> Result<SvnInfo, SvnError> {
-        let output = Command::new("svn")
-            .arg("info")
-            .arg(path)
-            .output()
-            .map_err(|e| SvnError::CommandFailed(e.to_string()))?;
+    /// Runs `svn info --xml` against one or more paths/URLs through `wrapper`
+    /// (so auth and non-interactive options apply) and parses the resulting
+    /// XML, returning one `SvnInfo` per `<entry>`.
+    pub fn new(wrapper: &SvnWrapper, paths: &[&str]) -> Result<Vec<SvnInfo>, SvnError> {
+        let mut command = wrapper.command("info");
+        command.arg("--xml").args(paths);
+        let output = wrapper.run(command)?;
 
         if !output.status.success() {
-            return Err(SvnError::CommandFailed(
+            return Err(classify_failure(
                 String::from_utf8_lossy(&output.stderr).to_string(),
             ));
         }
 
-        let output_str = String::from_utf8_lossy(&output.stdout).to_string();
-
-        let url = output_str.lines().find(|line| line.starts_with("URL: ")).map(|line| line[5..].to_owned());
-        let repository_root = output_str.lines().find(|line| line.starts_with("Repository Root: ")).map(|line| line[17..].to_owned());
-        let last_changed_author = output_str.lines().find(|line| line.starts_with("Last Changed Author: ")).map(|line| line[22..].to_owned());
-        let last_changed_rev = output_str.lines().find(|line| line.starts_with("Last Changed Rev: ")).and_then(|line| line[19..].parse::<u32>().ok());
-        let last_changed_date = output_str.lines().find(|line| line.starts_with("Last Changed Date: ")).map(|line| line[20..].to_owned());
+        let xml = String::from_utf8_lossy(&output.stdout);
+        let info: XmlInfo = quick_xml::de::from_str(&xml)?;
 
-        if let (Some(url), Some(repository_root), Some(last_changed_author), Some(last_changed_rev), Some(last_changed_date)) = (url, repository_root, last_changed_author, last_changed_rev, last_changed_date) {
-            Ok(SvnInfo {
-                url,
-                repository_root,
-                last_changed_author,
-                last_changed_rev,
-                last_changed_date,
-            })
-        } else {
-            Err(SvnError::CommandFailed("Unable to parse svn info output".to_owned()))
-        }
+        info.entries.into_iter().map(SvnInfo::try_from).collect()
     }
 }
 
+/// One `<entry>` of `svn status --xml`, as deserialized by `quick-xml`.
+#[derive(Deserialize)]
+struct XmlStatusEntry {
+    #[serde(rename = "@path")]
+    path: String,
+    #[serde(rename = "wc-status")]
+    wc_status: XmlWcStatus,
+    #[serde(rename = "repos-status")]
+    repos_status: Option<XmlReposStatus>,
+}
+
+#[derive(Deserialize)]
+struct XmlWcStatus {
+    #[serde(rename = "@item")]
+    item: String,
+    #[serde(rename = "@revision", default)]
+    revision: Option<u32>,
+}
+
+#[derive(Deserialize)]
+struct XmlReposStatus {
+    #[serde(rename = "@item")]
+    item: String,
+    #[serde(rename = "@revision", default)]
+    revision: Option<u32>,
+}
+
+#[derive(Deserialize)]
+struct XmlTarget {
+    #[serde(rename = "entry", default)]
+    entries: Vec<XmlStatusEntry>,
+}
+
+#[derive(Deserialize)]
+struct XmlStatus {
+    #[serde(rename = "target", default)]
+    targets: Vec<XmlTarget>,
+}
+
 pub struct SvnStatus {
     pub item: String,
     pub status: String,
     pub repository_status: String,
-    pub working_copy_status: String,
+    /// The working copy's revision, absent for unversioned/added items.
+    pub revision: Option<u32>,
+}
+
+impl From<XmlStatusEntry> for SvnStatus {
+    fn from(entry: XmlStatusEntry) -> Self {
+        SvnStatus {
+            item: entry.path,
+            status: entry.wc_status.item,
+            repository_status: entry
+                .repos_status
+                .map(|r| r.item)
+                .unwrap_or_default(),
+            revision: entry.wc_status.revision,
+        }
+    }
 }
 
 impl SvnStatus {
-    pub fn new(path: &str) -> Result<Vec<SvnStatus>, SvnError> {
-        let output = Command::new("svn")
-            .arg("status")
-            .arg("--show-updates")
-            .arg(path)
-            .output()
-            .map_err(|e| SvnError::CommandFailed(e.to_string()))?;
+    /// Runs `svn status --show-updates --xml` against one or more paths
+    /// through `wrapper` (so auth and non-interactive options apply) and
+    /// parses the resulting XML, returning one `SvnStatus` per `<entry>`.
+    pub fn new(wrapper: &SvnWrapper, paths: &[&str]) -> Result<Vec<SvnStatus>, SvnError> {
+        let mut command = wrapper.command("status");
+        command.arg("--show-updates").arg("--xml").args(paths);
+        let output = wrapper.run(command)?;
 
         if !output.status.success() {
-            return Err(SvnError::CommandFailed(
+            return Err(classify_failure(
                 String::from_utf8_lossy(&output.stderr).to_string(),
             ));
         }
 
-        let output_str = String::from_utf8_lossy(&output.stdout).to_string();
-        let mut statuses = Vec::new();
+        let xml = String::from_utf8_lossy(&output.stdout);
+        let status: XmlStatus = quick_xml::de::from_str(&xml)?;
 
-        for line in output_str.lines() {
-            let parts: Vec<&str> = line.split(' ').collect();
+        Ok(status
+            .targets
+            .into_iter()
+            .flat_map(|t| t.entries)
+            .map(SvnStatus::from)
+            .collect())
+    }
+}
+
+/// A single action queued on an [`SvnMucc`] builder.
+enum MuccOp {
+    Mv { src: String, dst: String },
+    Cp { rev: Revision, src: String, dst: String },
+    Rm { path: String },
+    Mkdir { path: String },
+    Put { local_path: String, dst: String },
+    Propset { name: String, value: String, path: String },
+}
 
-            if parts.len() < 2 {
-                continue;
+impl MuccOp {
+    fn push_args(&self, command: &mut Command) {
+        match self {
+            MuccOp::Mv { src, dst } => {
+                command.arg("mv").arg(src).arg(dst);
+            }
+            MuccOp::Cp { rev, src, dst } => {
+                command.arg("cp").arg(rev.as_arg()).arg(src).arg(dst);
+            }
+            MuccOp::Rm { path } => {
+                command.arg("rm").arg(path);
             }
+            MuccOp::Mkdir { path } => {
+                command.arg("mkdir").arg(path);
+            }
+            MuccOp::Put { local_path, dst } => {
+                command.arg("put").arg(local_path).arg(dst);
+            }
+            MuccOp::Propset { name, value, path } => {
+                command.arg("propset").arg(name).arg(value).arg(path);
+            }
+        }
+    }
+}
 
-            let status = parts[0];
-            let item = parts[1];
-            let repository_status = parts.get(2).map(|s| s.to_owned()).unwrap_or_default();
-            let working_copy_status = parts.get(3).map(|s| s.to_owned()).unwrap_or_default();
+/// Builds a single atomic server-side commit out of `mv`/`cp`/`rm`/`mkdir`/`put`
+/// operations on repository URLs, mirroring `svnmucc`'s Multiple-URL-Client
+/// workflow. Unlike [`SvnWrapper::commit`], this never touches a working copy.
+pub struct SvnMucc {
+    base_url: String,
+    ops: Vec<MuccOp>,
+}
 
-            statuses.push(SvnStatus {
-                item: item.to_owned(),
-                status: status.to_owned(),
-                repository_status: repository_status.to_owned(),
-               working_copy_status: working_copy_status.to_owned(),
-            });
+impl SvnMucc {
+    /// Starts a new multi-operation commit rooted at `base_url`.
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            base_url: base_url.to_owned(),
+            ops: Vec::new(),
+        }
+    }
+
+    /// Queues a copy of `src` at `rev` to `dst`.
+    pub fn cp(mut self, rev: Revision, src: &str, dst: &str) -> Self {
+        self.ops.push(MuccOp::Cp {
+            rev,
+            src: src.to_owned(),
+            dst: dst.to_owned(),
+        });
+        self
+    }
+
+    /// Queues removal of `path`.
+    pub fn rm(mut self, path: &str) -> Self {
+        self.ops.push(MuccOp::Rm {
+            path: path.to_owned(),
+        });
+        self
+    }
+
+    /// Queues a move of `src` to `dst`.
+    pub fn mv(mut self, src: &str, dst: &str) -> Self {
+        self.ops.push(MuccOp::Mv {
+            src: src.to_owned(),
+            dst: dst.to_owned(),
+        });
+        self
+    }
+
+    /// Queues creation of a new directory at `path`.
+    pub fn mkdir(mut self, path: &str) -> Self {
+        self.ops.push(MuccOp::Mkdir {
+            path: path.to_owned(),
+        });
+        self
+    }
+
+    /// Queues uploading the contents of `local_path` as the new contents of `dst`.
+    pub fn put(mut self, local_path: &str, dst: &str) -> Self {
+        self.ops.push(MuccOp::Put {
+            local_path: local_path.to_owned(),
+            dst: dst.to_owned(),
+        });
+        self
+    }
+
+    /// Queues setting property `name` to `value` on `path`.
+    pub fn propset(mut self, name: &str, value: &str, path: &str) -> Self {
+        self.ops.push(MuccOp::Propset {
+            name: name.to_owned(),
+            value: value.to_owned(),
+            path: path.to_owned(),
+        });
+        self
+    }
+
+    /// Runs `svnmucc` with all queued operations, landing them in a single
+    /// new revision under `message`. Routed through `wrapper` so auth and
+    /// non-interactive options apply, the same way they do for
+    /// [`SvnWrapper`]'s own methods.
+    pub fn commit(self, wrapper: &SvnWrapper, message: &str) -> Result<(), SvnError> {
+        let mut command = wrapper.svnmucc_command();
+        command.arg("-U").arg(&self.base_url).arg("-m").arg(message);
+
+        for op in &self.ops {
+            op.push_args(&mut command);
         }
 
-        Ok(statuses)
+        let output = wrapper.run(command)?;
+
+        if !output.status.success() {
+            return Err(classify_failure(
+                String::from_utf8_lossy(&output.stderr).to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// Line-ending style that [`translate`] can normalize content to.
+pub enum Eol {
+    /// `\n`
+    Lf,
+    /// `\r\n`
+    Crlf,
+    /// `\r`
+    Cr,
+}
+
+impl Eol {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Eol::Lf => "\n",
+            Eol::Crlf => "\r\n",
+            Eol::Cr => "\r",
+        }
+    }
+}
+
+/// Which `$Keyword$` substitutions [`translate`] should expand, mirroring the
+/// keyword names accepted by the `svn:keywords` property.
+#[derive(Default, Clone, Copy)]
+pub struct KeywordSet {
+    /// `$Rev$` / `$Revision$`
+    pub rev: bool,
+    /// `$Author$`
+    pub author: bool,
+    /// `$Date$`
+    pub date: bool,
+    /// `$URL$` / `$HeadURL$`
+    pub url: bool,
+    /// `$Id$`
+    pub id: bool,
+}
+
+/// Mirrors `svn_subst_copy_and_translate`: substitutes enabled `$Keyword$` /
+/// `$Keyword: ... $` markers in `content` with values taken from `info`, and,
+/// when `eol` is given, normalizes every line ending to that style.
+///
+/// If `content` mixes line-ending styles and `eol` is `Some`, this fails with
+/// [`SvnError::MixedEol`] unless `repair` is set, in which case every line
+/// ending is rewritten to the requested style.
+pub fn translate(
+    content: &[u8],
+    info: &SvnInfo,
+    keywords: KeywordSet,
+    eol: Option<Eol>,
+    repair: bool,
+) -> Result<Vec<u8>, SvnError> {
+    let text = std::str::from_utf8(content).map_err(SvnError::Malformed)?;
+    let text = normalize_eol(text, eol.as_ref(), repair)?;
+
+    Ok(expand_keywords(&text, info, &keywords).into_bytes())
+}
+
+fn normalize_eol(text: &str, eol: Option<&Eol>, repair: bool) -> Result<String, SvnError> {
+    let Some(eol) = eol else {
+        return Ok(text.to_owned());
+    };
+
+    let mut lines = Vec::new();
+    let mut saw_lf = false;
+    let mut saw_crlf = false;
+    let mut saw_cr = false;
+
+    let bytes = text.as_bytes();
+    let mut start = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'\r' if bytes.get(i + 1) == Some(&b'\n') => {
+                lines.push(&text[start..i]);
+                saw_crlf = true;
+                i += 2;
+                start = i;
+            }
+            b'\r' => {
+                lines.push(&text[start..i]);
+                saw_cr = true;
+                i += 1;
+                start = i;
+            }
+            b'\n' => {
+                lines.push(&text[start..i]);
+                saw_lf = true;
+                i += 1;
+                start = i;
+            }
+            _ => i += 1,
+        }
+    }
+    let trailing = &text[start..];
+
+    if [saw_lf, saw_crlf, saw_cr].iter().filter(|seen| **seen).count() > 1 && !repair {
+        return Err(SvnError::MixedEol);
+    }
+
+    let mut out = String::with_capacity(text.len());
+    for line in lines {
+        out.push_str(line);
+        out.push_str(eol.as_str());
+    }
+    out.push_str(trailing);
+
+    Ok(out)
+}
+
+const MAX_KEYWORD_SPAN: usize = 255;
+
+fn expand_keywords(text: &str, info: &SvnInfo, keywords: &KeywordSet) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(dollar) = rest.find('$') {
+        out.push_str(&rest[..dollar]);
+        let after = &rest[dollar + 1..];
+
+        let expanded = after.find('$').and_then(|close| {
+            let inner = &after[..close];
+            let fits = inner.len() <= MAX_KEYWORD_SPAN && !inner.contains('\n');
+            let name = inner.split(':').next().unwrap_or(inner).trim();
+
+            if fits {
+                keyword_value(name, info, keywords).map(|value| (name, value, close))
+            } else {
+                None
+            }
+        });
+
+        match expanded {
+            Some((name, value, close)) => {
+                out.push('$');
+                out.push_str(name);
+                out.push_str(": ");
+                out.push_str(&value);
+                out.push_str(" $");
+                rest = &after[close + 1..];
+            }
+            None => {
+                out.push('$');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+
+    out
+}
+
+fn keyword_value(name: &str, info: &SvnInfo, keywords: &KeywordSet) -> Option<String> {
+    match name {
+        "Rev" | "Revision" if keywords.rev => Some(info.last_changed_rev.to_string()),
+        "Author" if keywords.author => Some(info.last_changed_author.clone()),
+        "Date" if keywords.date => Some(format_svn_date(&info.last_changed_date)),
+        "URL" | "HeadURL" if keywords.url => Some(info.url.clone()),
+        "Id" if keywords.id => Some(format!(
+            "{} {} {} {}",
+            filename_from_url(&info.url),
+            info.last_changed_rev,
+            format_svn_date(&info.last_changed_date),
+            info.last_changed_author,
+        )),
+        _ => None,
+    }
+}
+
+fn format_svn_date(date: &DateTime<Utc>) -> String {
+    date.format("%Y-%m-%d %H:%M:%S%z").to_string()
+}
+
+fn filename_from_url(url: &str) -> &str {
+    url.rsplit('/').next().unwrap_or(url)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify_failure_detects_lock_required() {
+        let err = classify_failure(
+            "svn: E195022: '/repo/trunk/file.txt' is locked by user 'jdoe'".to_owned(),
+        );
+        assert!(matches!(err, SvnError::LockRequired(_)));
+    }
+
+    #[test]
+    fn classify_failure_leaves_working_copy_cleanup_errors_alone() {
+        let err = classify_failure(
+            "svn: E155004: Working copy '/repo' locked; run 'svn cleanup'".to_owned(),
+        );
+        assert!(matches!(err, SvnError::CommandFailed(_)));
+    }
+
+    #[test]
+    fn svn_status_xml_parses_wc_status_revision() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<status>
+    <target path=".">
+        <entry path="trunk/file.txt">
+            <wc-status item="modified" revision="42"></wc-status>
+        </entry>
+    </target>
+</status>"#;
+
+        let status: XmlStatus = quick_xml::de::from_str(xml).unwrap();
+        let entry = status
+            .targets
+            .into_iter()
+            .flat_map(|t| t.entries)
+            .next()
+            .unwrap();
+        let status = SvnStatus::from(entry);
+
+        assert_eq!(status.revision, Some(42));
+    }
+
+    #[test]
+    fn svn_wrapper_builder_threads_options_into_command() {
+        let wrapper = SvnWrapper::builder()
+            .username("jdoe")
+            .password("hunter2")
+            .non_interactive(true)
+            .trust_server_cert_failures("unknown-ca")
+            .config_dir("/tmp/svn-config")
+            .build();
+
+        let command = wrapper.command("status");
+        let args: Vec<&str> = command.get_args().map(|a| a.to_str().unwrap()).collect();
+
+        assert_eq!(
+            args,
+            vec![
+                "status",
+                "--username",
+                "jdoe",
+                "--password-from-stdin",
+                "--non-interactive",
+                "--trust-server-cert-failures",
+                "unknown-ca",
+                "--config-dir",
+                "/tmp/svn-config",
+            ]
+        );
+        assert!(!args.iter().any(|arg| arg.contains("hunter2")));
+    }
+
+    #[test]
+    fn svn_wrapper_builder_defaults_to_no_global_options() {
+        let wrapper = SvnWrapper::new();
+        let command = wrapper.command("status");
+        let args: Vec<&str> = command.get_args().map(|a| a.to_str().unwrap()).collect();
+
+        assert_eq!(args, vec!["status"]);
+    }
+
+    #[test]
+    fn svn_info_xml_parses_into_typed_timestamp() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<info>
+    <entry kind="file" path="file.txt" revision="42">
+        <url>https://svn.example.com/repo/trunk/file.txt</url>
+        <repository>
+            <root>https://svn.example.com/repo</root>
+        </repository>
+        <commit revision="42">
+            <author>jdoe</author>
+            <date>2024-01-02T03:04:05.000000Z</date>
+        </commit>
+    </entry>
+</info>"#;
+
+        let parsed: XmlInfo = quick_xml::de::from_str(xml).unwrap();
+        let info = SvnInfo::try_from(parsed.entries.into_iter().next().unwrap()).unwrap();
+
+        assert_eq!(info.url, "https://svn.example.com/repo/trunk/file.txt");
+        assert_eq!(info.last_changed_rev, 42);
+        assert_eq!(info.last_changed_author, "jdoe");
+        assert_eq!(
+            info.last_changed_date,
+            DateTime::parse_from_rfc3339("2024-01-02T03:04:05Z")
+                .unwrap()
+                .with_timezone(&Utc)
+        );
+    }
+
+    #[test]
+    fn parse_notification_handles_padded_status_column() {
+        let notification = parse_notification("A    trunk/file.txt").unwrap();
+        assert!(matches!(notification.action, NotificationAction::Added));
+        assert_eq!(notification.path, "trunk/file.txt");
+    }
+
+    #[test]
+    fn parse_notification_handles_single_space() {
+        let notification = parse_notification("U src/main.rs").unwrap();
+        assert!(matches!(notification.action, NotificationAction::Updated));
+        assert_eq!(notification.path, "src/main.rs");
+    }
+
+    #[test]
+    fn parse_notification_rejects_unrecognized_line() {
+        assert!(parse_notification("Checked out revision 5.").is_none());
+    }
+
+    #[test]
+    fn parse_final_revision_recognizes_checked_out() {
+        assert_eq!(
+            parse_final_revision("Checked out revision 42.", "Checked out revision "),
+            Some(42)
+        );
+    }
+
+    #[test]
+    fn parse_final_revision_recognizes_updated_to() {
+        assert_eq!(
+            parse_final_revision("Updated to revision 7.", "Updated to revision "),
+            Some(7)
+        );
+    }
+
+    #[test]
+    fn parse_final_revision_recognizes_no_op_update() {
+        assert_eq!(
+            parse_final_revision("At revision 7.", "Updated to revision "),
+            Some(7)
+        );
+    }
+
+    fn sample_info() -> SvnInfo {
+        SvnInfo {
+            url: "https://svn.example.com/repo/trunk/file.txt".to_owned(),
+            repository_root: "https://svn.example.com/repo".to_owned(),
+            last_changed_author: "jdoe".to_owned(),
+            last_changed_rev: 42,
+            last_changed_date: DateTime::parse_from_rfc3339("2024-01-02T03:04:05Z")
+                .unwrap()
+                .with_timezone(&Utc),
+            last_changed_date_raw: "2024-01-02T03:04:05.000000Z".to_owned(),
+            lock: None,
+        }
+    }
+
+    #[test]
+    fn translate_expands_enabled_keywords_and_ignores_disabled_ones() {
+        let info = sample_info();
+        let keywords = KeywordSet {
+            rev: true,
+            author: true,
+            ..Default::default()
+        };
+
+        let out = translate(b"$Rev$ $Author$ $Date$", &info, keywords, None, false).unwrap();
+        let out = String::from_utf8(out).unwrap();
+
+        assert_eq!(out, "$Rev: 42 $ $Author: jdoe $ $Date$");
+    }
+
+    #[test]
+    fn translate_rejects_mixed_eol_without_repair() {
+        let info = sample_info();
+        let result = translate(b"a\nb\r\n", &info, KeywordSet::default(), Some(Eol::Lf), false);
+
+        assert!(matches!(result, Err(SvnError::MixedEol)));
+    }
+
+    #[test]
+    fn translate_repairs_mixed_eol_to_requested_style() {
+        let info = sample_info();
+        let out = translate(b"a\nb\r\nc\r", &info, KeywordSet::default(), Some(Eol::Lf), true)
+            .unwrap();
+
+        assert_eq!(out, b"a\nb\nc\n");
+    }
+
+    #[test]
+    fn translate_rejects_non_utf8_content() {
+        let info = sample_info();
+        let result = translate(&[0xff, 0xfe], &info, KeywordSet::default(), None, false);
+
+        assert!(matches!(result, Err(SvnError::Malformed(_))));
     }
 }